@@ -0,0 +1,62 @@
+use crate::ray::Ray;
+use crate::vec::Vec3;
+
+/// An axis-aligned bounding box, used to cheaply reject rays that cannot possibly hit an object
+/// before paying for its full intersection test.
+#[derive(Debug, Clone, Copy)]
+pub struct AABB {
+    pub min: Vec3,
+    pub max: Vec3,
+}
+
+impl AABB {
+    pub fn new(min: Vec3, max: Vec3) -> Self {
+        Self { min, max }
+    }
+
+    /// Return the smallest box that contains both `self` and `other`.
+    pub fn merge(&self, other: AABB) -> AABB {
+        let min = Vec3::new(
+            self.min.x.min(other.min.x),
+            self.min.y.min(other.min.y),
+            self.min.z.min(other.min.z),
+        );
+        let max = Vec3::new(
+            self.max.x.max(other.max.x),
+            self.max.y.max(other.max.y),
+            self.max.z.max(other.max.z),
+        );
+
+        AABB::new(min, max)
+    }
+
+    /// Slab test for ray/box intersection. Returns `false` as soon as the intervals of the axes
+    /// tested so far no longer overlap, so a miss can be rejected before all three axes are tested.
+    pub fn hit(&self, r: &Ray, t_min: f64, t_max: f64) -> bool {
+        let mut t_min = t_min;
+        let mut t_max = t_max;
+
+        for (origin, dir, min, max) in [
+            (r.origin.x, r.direction.x, self.min.x, self.max.x),
+            (r.origin.y, r.direction.y, self.min.y, self.max.y),
+            (r.origin.z, r.direction.z, self.min.z, self.max.z),
+        ] {
+            let inv_d = 1.0 / dir;
+            let mut t0 = (min - origin) * inv_d;
+            let mut t1 = (max - origin) * inv_d;
+
+            if inv_d < 0.0 {
+                std::mem::swap(&mut t0, &mut t1);
+            }
+
+            t_min = t0.max(t_min);
+            t_max = t1.min(t_max);
+
+            if t_max <= t_min {
+                return false;
+            }
+        }
+
+        true
+    }
+}
@@ -0,0 +1,113 @@
+use rand::Rng;
+
+use crate::aabb::AABB;
+use crate::hittable::{HitRecord, Hittable, HittableList};
+use crate::ray::Ray;
+
+/// Return the minimum corner of `bbox` along `axis` (0 = x, 1 = y, 2 = z).
+fn min_on_axis(bbox: &AABB, axis: usize) -> f64 {
+    match axis {
+        0 => bbox.min.x,
+        1 => bbox.min.y,
+        _ => bbox.min.z,
+    }
+}
+
+/// A node in a bounding volume hierarchy. Wrapping a `HittableList` in a `BVHNode` turns the
+/// linear scan in `HittableList::hit` into a binary search over nested bounding boxes, so scenes
+/// with thousands of objects stay fast.
+pub struct BVHNode {
+    left: Option<Box<dyn Hittable>>,
+    right: Option<Box<dyn Hittable>>,
+    bbox: AABB,
+}
+
+impl BVHNode {
+    pub fn new(list: HittableList, t0: f64, t1: f64) -> Self {
+        Self::build(list.list, t0, t1)
+    }
+
+    fn build(mut objects: Vec<Box<dyn Hittable>>, t0: f64, t1: f64) -> Self {
+        let axis = rand::thread_rng().gen_range(0..3);
+
+        objects.sort_by(|a, b| {
+            let box_a = a
+                .bounding_box(t0, t1)
+                .expect("no bounding box in BVHNode constructor");
+            let box_b = b
+                .bounding_box(t0, t1)
+                .expect("no bounding box in BVHNode constructor");
+
+            min_on_axis(&box_a, axis)
+                .partial_cmp(&min_on_axis(&box_b, axis))
+                .unwrap()
+        });
+
+        match objects.len() {
+            1 => {
+                let bbox = objects[0]
+                    .bounding_box(t0, t1)
+                    .expect("no bounding box in BVHNode constructor");
+                let only = objects.pop().unwrap();
+
+                Self {
+                    left: Some(only),
+                    right: None,
+                    bbox,
+                }
+            }
+            2 => {
+                let box_left = objects[0]
+                    .bounding_box(t0, t1)
+                    .expect("no bounding box in BVHNode constructor");
+                let box_right = objects[1]
+                    .bounding_box(t0, t1)
+                    .expect("no bounding box in BVHNode constructor");
+
+                let right = objects.pop().unwrap();
+                let left = objects.pop().unwrap();
+
+                Self {
+                    left: Some(left),
+                    right: Some(right),
+                    bbox: box_left.merge(box_right),
+                }
+            }
+            _ => {
+                let right_half = objects.split_off(objects.len() / 2);
+                let left_half = objects;
+
+                let left = Self::build(left_half, t0, t1);
+                let right = Self::build(right_half, t0, t1);
+                let bbox = left.bbox.merge(right.bbox);
+
+                Self {
+                    left: Some(Box::new(left)),
+                    right: Some(Box::new(right)),
+                    bbox,
+                }
+            }
+        }
+    }
+}
+
+impl Hittable for BVHNode {
+    fn hit(&self, r: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        if !self.bbox.hit(r, t_min, t_max) {
+            return None;
+        }
+
+        let hit_left = self.left.as_ref().and_then(|node| node.hit(r, t_min, t_max));
+        let closest = hit_left.as_ref().map_or(t_max, |rec| rec.t);
+        let hit_right = self
+            .right
+            .as_ref()
+            .and_then(|node| node.hit(r, t_min, closest));
+
+        hit_right.or(hit_left)
+    }
+
+    fn bounding_box(&self, _t0: f64, _t1: f64) -> Option<AABB> {
+        Some(self.bbox)
+    }
+}
@@ -0,0 +1,80 @@
+use rand::distributions::{Distribution, Uniform};
+
+use crate::aabb::AABB;
+use crate::hittable::{HitRecord, Hittable};
+use crate::material::{Isotropic, Material};
+use crate::ray::Ray;
+use crate::texture::Texture;
+use crate::vec::Vec3;
+
+/// A constant-density volume (smoke, fog, cloud) wrapped around any `Hittable` boundary. A ray
+/// passing through the boundary has a constant probability per unit distance of scattering off
+/// the medium, independent of where it enters or its angle of incidence.
+pub struct ConstantMedium {
+    boundary: Box<dyn Hittable>,
+    neg_inv_density: f64,
+    phase: Material,
+}
+
+impl ConstantMedium {
+    pub fn new(boundary: Box<dyn Hittable>, density: f64, albedo: impl Texture + 'static) -> Self {
+        Self {
+            boundary,
+            neg_inv_density: -1.0 / density,
+            phase: Material::Isotropic(Isotropic::new(albedo)),
+        }
+    }
+}
+
+impl Hittable for ConstantMedium {
+    fn hit(&self, r: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let mut rec1 = self.boundary.hit(r, -std::f64::INFINITY, std::f64::INFINITY)?;
+        let mut rec2 = self
+            .boundary
+            .hit(r, rec1.t + 0.0001, std::f64::INFINITY)?;
+
+        if rec1.t < t_min {
+            rec1.t = t_min;
+        }
+        if rec2.t > t_max {
+            rec2.t = t_max;
+        }
+
+        if rec1.t >= rec2.t {
+            return None;
+        }
+
+        if rec1.t < 0.0 {
+            rec1.t = 0.0;
+        }
+
+        let ray_length = r.direction.length();
+        let distance_inside_boundary = (rec2.t - rec1.t) * ray_length;
+
+        let dist = Uniform::new(0.0, 1.0);
+        let mut rng = rand::thread_rng();
+        let hit_distance = self.neg_inv_density * dist.sample(&mut rng).ln();
+
+        if hit_distance > distance_inside_boundary {
+            return None;
+        }
+
+        let t = rec1.t + hit_distance / ray_length;
+        let p = r.point_at(t);
+
+        // Normal and front_face are meaningless inside a volume; Isotropic::scatter ignores both.
+        Some(HitRecord::new(
+            0.0,
+            0.0,
+            t,
+            p,
+            Vec3::new(1.0, 0.0, 0.0),
+            true,
+            &self.phase,
+        ))
+    }
+
+    fn bounding_box(&self, t0: f64, t1: f64) -> Option<AABB> {
+        self.boundary.bounding_box(t0, t1)
+    }
+}
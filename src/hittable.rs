@@ -23,7 +23,7 @@ pub struct HitRecord<'a> {
     pub p: Vec3,          // Intersecting ray.
     pub normal: Vec3,     // Normal vector of the intersected object.
     pub front_face: bool, // Flag for detemrining whether the ray hit the inside or outside of an objeect.
-    pub material: &'a dyn Material, // The material assigned to the intersected object.
+    pub material: &'a Material, // The material assigned to the intersected object.
 }
 
 impl<'a> HitRecord<'a> {
@@ -34,7 +34,7 @@ impl<'a> HitRecord<'a> {
         p: Vec3,
         normal: Vec3,
         front_face: bool,
-        material: &'a dyn Material,
+        material: &'a Material,
     ) -> Self {
         Self {
             u,
@@ -51,11 +51,15 @@ impl<'a> HitRecord<'a> {
 /// A HittableList stores a collection of HitRecords and has functionality for finding the closes hit to the camera.
 pub struct HittableList {
     pub list: Vec<Box<dyn Hittable>>,
+    pub background: Colour, // Radiance returned for rays that miss every object in the list.
 }
 
 impl HittableList {
-    pub fn new() -> Self {
-        HittableList { list: Vec::new() }
+    pub fn new(background: Colour) -> Self {
+        HittableList {
+            list: Vec::new(),
+            background,
+        }
     }
 
     pub fn push(&mut self, item: Box<dyn Hittable>) {
@@ -1,3 +1,5 @@
+use std::f64::consts::PI;
+
 use rand::distributions::{Distribution, Uniform};
 use rand::rngs::ThreadRng;
 
@@ -14,48 +16,80 @@ fn schlick(cos: f64, ior: f64) -> f64 {
     r0 + (1.0 - r0) * (1.0 - cos).powi(5)
 }
 
-pub trait Material: Sync {
-    /// Given an input ray and a record of a collision, calculate the reflected ray and the Colour of the point.
-    fn scatter(
+/// The material assigned to a hittable. Stored as an enum rather than a `Box<dyn Material>` so
+/// the scatter/hit loop, which runs once per bounce, dispatches with a `match` the compiler can
+/// inline instead of an indirect vtable call.
+pub enum Material {
+    Lambertian(Lambertian),
+    Metal(Metal),
+    Dielectric(Dielectric),
+    DiffuseLight(DiffuseLight),
+    Isotropic(Isotropic),
+}
+
+impl Material {
+    /// Given an input ray and a record of a collision, calculate the reflected ray and the Colour
+    /// of the point, plus the scattering PDF the ray was sampled with. `Metal` and `Dielectric`
+    /// sample their single reflected/refracted direction deterministically, so they return `None`
+    /// for the PDF to signal the render loop shouldn't mix in light sampling for them.
+    pub fn scatter(
         &self,
         rec: &HitRecord,
         ray: &Ray,
         dist: &Uniform<f64>,
         rng: &mut ThreadRng,
-    ) -> Option<(Ray, Colour)>;
+    ) -> Option<(Ray, Colour, Option<f64>)> {
+        match self {
+            Material::Lambertian(m) => m.scatter(rec, ray, dist, rng),
+            Material::Metal(m) => m.scatter(rec, ray, dist, rng),
+            Material::Dielectric(m) => m.scatter(rec, ray, dist, rng),
+            Material::DiffuseLight(m) => m.scatter(rec, ray, dist, rng),
+            Material::Isotropic(m) => m.scatter(rec, ray, dist, rng),
+        }
+    }
 
     /// Return how much light is emitted from the material. Black for anything that isn't a light source.
-    fn emitted(&self, u: f64, v: f64, p: Vec3, dist: &Uniform<f64>, rng: &mut ThreadRng) -> Colour;
+    pub fn emitted(&self, u: f64, v: f64, p: Vec3, dist: &Uniform<f64>, rng: &mut ThreadRng) -> Colour {
+        match self {
+            Material::Lambertian(m) => m.emitted(u, v, p, dist, rng),
+            Material::Metal(m) => m.emitted(u, v, p, dist, rng),
+            Material::Dielectric(m) => m.emitted(u, v, p, dist, rng),
+            Material::DiffuseLight(m) => m.emitted(u, v, p, dist, rng),
+            Material::Isotropic(m) => m.emitted(u, v, p, dist, rng),
+        }
+    }
 }
 
 /// Lambertian materials a diffuse. For this program, they reflect 50% of light.
-#[derive(Debug, Clone, Copy)]
-pub struct Lambertian<T: Texture> {
-    albedo: T,
+pub struct Lambertian {
+    albedo: Box<dyn Texture>,
 }
 
-impl<T: Texture> Lambertian<T> {
-    pub fn new(albedo: T) -> Self {
-        Self { albedo }
+impl Lambertian {
+    pub fn new(albedo: impl Texture + 'static) -> Self {
+        Self {
+            albedo: Box::new(albedo),
+        }
     }
-}
 
-impl<T: Texture> Material for Lambertian<T> {
     fn scatter(
         &self,
         rec: &HitRecord,
         ray: &Ray,
         dist: &Uniform<f64>,
         rng: &mut ThreadRng,
-    ) -> Option<(Ray, Colour)> {
+    ) -> Option<(Ray, Colour, Option<f64>)> {
         let scattered_ray = Ray::new(
             rec.p,
             rec.normal + Vec3::random_in_unit_sphere(dist, rng),
             ray.time,
         );
 
+        let cos_theta = scattered_ray.direction.normalise().dot(rec.normal);
+        let pdf = cos_theta / PI;
+
         let attenuation = self.albedo.value(rec.u, rec.v, rec.p);
-        Some((scattered_ray, attenuation))
+        Some((scattered_ray, attenuation, Some(pdf)))
     }
 
     fn emitted(
@@ -81,16 +115,14 @@ impl Metal {
     pub fn new(albedo: Colour, fuzz: f64) -> Self {
         Self { albedo, fuzz }
     }
-}
 
-impl Material for Metal {
     fn scatter(
         &self,
         rec: &HitRecord,
         ray: &Ray,
         dist: &Uniform<f64>,
         rng: &mut ThreadRng,
-    ) -> Option<(Ray, Colour)> {
+    ) -> Option<(Ray, Colour, Option<f64>)> {
         let reflected_ray = reflect(ray.direction.normalise(), rec.normal);
 
         if reflected_ray.dot(rec.normal) > 0.0 {
@@ -99,7 +131,7 @@ impl Material for Metal {
                 reflected_ray + Vec3::random_in_unit_sphere(dist, rng) * self.fuzz,
                 ray.time,
             );
-            Some((scattered_ray, self.albedo))
+            Some((scattered_ray, self.albedo, None))
         } else {
             None
         }
@@ -127,16 +159,14 @@ impl Dielectric {
     pub fn new(ior: f64) -> Self {
         Self { ior }
     }
-}
 
-impl Material for Dielectric {
     fn scatter(
         &self,
         rec: &HitRecord,
         ray: &Ray,
         dist: &Uniform<f64>,
         rng: &mut ThreadRng,
-    ) -> Option<(Ray, Colour)> {
+    ) -> Option<(Ray, Colour, Option<f64>)> {
         let attenuation = Colour::new(1.0, 1.0, 1.0);
 
         let ni_over_nt = if rec.front_face {
@@ -157,19 +187,19 @@ impl Material for Dielectric {
                 if dist.sample(rng) < reflect_prob {
                     let reflected = reflect(unit_direction, rec.normal);
                     let scattered = Ray::new(rec.p, reflected, ray.time);
-                    return Some((scattered, attenuation));
+                    return Some((scattered, attenuation, None));
                 }
 
                 // Otherwise refract the ray
                 let scattered = Ray::new(rec.p, refracted, ray.time);
-                Some((scattered, attenuation))
+                Some((scattered, attenuation, None))
             }
 
             // Reflect the ray if no refraction is possible
             None => {
                 let reflected = reflect(unit_direction, rec.normal);
                 let scattered = Ray::new(rec.p, reflected, ray.time);
-                Some((scattered, attenuation))
+                Some((scattered, attenuation, None))
             }
         }
     }
@@ -187,25 +217,24 @@ impl Material for Dielectric {
 }
 
 /// DiffuseLight materials emit light of a specified colour.
-#[derive(Debug, Clone, Copy)]
-pub struct DiffuseLight<T: Texture> {
-    emit: T,
+pub struct DiffuseLight {
+    emit: Box<dyn Texture>,
 }
 
-impl<T: Texture> DiffuseLight<T> {
-    pub fn new(emit: T) -> Self {
-        Self { emit }
+impl DiffuseLight {
+    pub fn new(emit: impl Texture + 'static) -> Self {
+        Self {
+            emit: Box::new(emit),
+        }
     }
-}
 
-impl<T: Texture> Material for DiffuseLight<T> {
     fn scatter(
         &self,
         _rec: &HitRecord,
         _ray: &Ray,
         _dist: &Uniform<f64>,
         _rng: &mut ThreadRng,
-    ) -> Option<(Ray, Colour)> {
+    ) -> Option<(Ray, Colour, Option<f64>)> {
         None
     }
 
@@ -220,3 +249,43 @@ impl<T: Texture> Material for DiffuseLight<T> {
         self.emit.value(u, v, p)
     }
 }
+
+/// Isotropic materials scatter light equally in every direction. Used as the phase function for
+/// participating media such as smoke and fog, where light bounces around inside the volume with
+/// no preferred direction.
+pub struct Isotropic {
+    albedo: Box<dyn Texture>,
+}
+
+impl Isotropic {
+    pub fn new(albedo: impl Texture + 'static) -> Self {
+        Self {
+            albedo: Box::new(albedo),
+        }
+    }
+
+    fn scatter(
+        &self,
+        rec: &HitRecord,
+        ray: &Ray,
+        dist: &Uniform<f64>,
+        rng: &mut ThreadRng,
+    ) -> Option<(Ray, Colour, Option<f64>)> {
+        // Isotropic scattering is sampled uniformly over the sphere, so it is treated the same as
+        // the specular materials: deterministic given the sample, no mixture PDF to divide out.
+        let scattered_ray = Ray::new(rec.p, Vec3::random_in_unit_sphere(dist, rng), ray.time);
+        let attenuation = self.albedo.value(rec.u, rec.v, rec.p);
+        Some((scattered_ray, attenuation, None))
+    }
+
+    fn emitted(
+        &self,
+        _u: f64,
+        _v: f64,
+        _p: Vec3,
+        _dist: &Uniform<f64>,
+        _rng: &mut ThreadRng,
+    ) -> Colour {
+        Colour::new(0.0, 0.0, 0.0)
+    }
+}
@@ -0,0 +1,97 @@
+use crate::aabb::AABB;
+use crate::hittable::{HitRecord, Hittable};
+use crate::material::Material;
+use crate::ray::Ray;
+use crate::vec::Vec3;
+
+/// A sphere whose center moves linearly between `center0` at `time0` and `center1` at `time1`.
+/// Combined with a camera that samples a random ray time per pixel, this smears the sphere across
+/// the frame along its path of motion.
+pub struct MovingSphere {
+    center0: Vec3,
+    center1: Vec3,
+    time0: f64,
+    time1: f64,
+    radius: f64,
+    material: Material,
+}
+
+impl MovingSphere {
+    pub fn new(
+        center0: Vec3,
+        center1: Vec3,
+        time0: f64,
+        time1: f64,
+        radius: f64,
+        material: Material,
+    ) -> Self {
+        Self {
+            center0,
+            center1,
+            time0,
+            time1,
+            radius,
+            material,
+        }
+    }
+
+    /// Calculate the sphere's center at a given point in time.
+    pub fn center(&self, time: f64) -> Vec3 {
+        self.center0
+            + (self.center1 - self.center0) * ((time - self.time0) / (self.time1 - self.time0))
+    }
+}
+
+impl Hittable for MovingSphere {
+    fn hit(&self, r: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let center = self.center(r.time);
+        let oc = r.origin - center;
+
+        let a = r.direction.dot(r.direction);
+        let b = oc.dot(r.direction);
+        let c = oc.dot(oc) - self.radius * self.radius;
+        let discriminant = b * b - a * c;
+
+        if discriminant < 0.0 {
+            return None;
+        }
+
+        let sqrt_d = discriminant.sqrt();
+
+        let mut root = (-b - sqrt_d) / a;
+        if root < t_min || root > t_max {
+            root = (-b + sqrt_d) / a;
+            if root < t_min || root > t_max {
+                return None;
+            }
+        }
+
+        let p = r.point_at(root);
+        let outward_normal = (p - center) / self.radius;
+        let front_face = r.direction.dot(outward_normal) < 0.0;
+        let normal = if front_face {
+            outward_normal
+        } else {
+            -outward_normal
+        };
+
+        Some(HitRecord::new(
+            0.0,
+            0.0,
+            root,
+            p,
+            normal,
+            front_face,
+            &self.material,
+        ))
+    }
+
+    fn bounding_box(&self, t0: f64, t1: f64) -> Option<AABB> {
+        let radius = Vec3::new(self.radius, self.radius, self.radius);
+
+        let box0 = AABB::new(self.center(t0) - radius, self.center(t0) + radius);
+        let box1 = AABB::new(self.center(t1) - radius, self.center(t1) + radius);
+
+        Some(box0.merge(box1))
+    }
+}